@@ -1,53 +1,467 @@
-use crate::graph::{Connection, Graph};
-use lazy_static::lazy_static;
-use std::collections::HashSet;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 
-lazy_static! {
-    static ref VALID_UNITS: HashSet<&'static str> =
-        HashSet::from(["m", "in", "hr", "ft", "min", "sec"]);
-}
+pub use crate::graph::ReverseWeight;
+use crate::graph::{Connection, Graph, Inconsistency as GraphInconsistency, VertexScale};
 
 pub struct ConversionGraph {
     graph: Graph<Unit>,
+    // Per-vertex scale factors relative to their connected component, recomputed whenever the
+    // graph is mutated. Caching these lets `convert` answer in O(1) instead of re-running a BFS
+    // over the graph on every call.
+    scales: HashMap<Unit, VertexScale>,
+    // Set once any weak or one-directional fact is registered. A single scale factor per unit
+    // can't soundly represent those (a fallback-only or one-way relationship isn't expressible as
+    // a symmetric ratio), so once this is set, `try_convert` falls back to a live graph traversal
+    // instead of the cached scales.
+    has_asymmetric_facts: bool,
 }
 
 impl ConversionGraph {
-    pub fn new(facts: Vec<UnitConversion>) -> Self {
-        Self {
-            graph: Graph::new(
-                facts
-                    .iter()
-                    .map(|f| f.into())
-                    .collect::<Vec<Connection<Unit>>>(),
-            ),
+    /// Builds a graph from a set of conversion facts. Fails if any fact is between compound
+    /// units (e.g. `km/h`) - facts must be between plain units; only queries may be compound.
+    pub fn new(facts: Vec<UnitConversion>) -> Result<Self, ConversionError> {
+        let has_asymmetric_facts = facts.iter().any(UnitConversion::is_asymmetric);
+        let connections = facts
+            .iter()
+            .map(Connection::try_from)
+            .collect::<Result<Vec<Connection<Unit>>, ConversionError>>()?;
+        let graph = Graph::new(connections);
+        let (scales, _) = graph.compute_scales();
+
+        Ok(Self {
+            graph,
+            scales,
+            has_asymmetric_facts,
+        })
+    }
+
+    /// Registers `unit` with the graph if it isn't already known, so that later conversions
+    /// involving it don't fail with [`ConversionError::UnknownUnit`][ConversionError::UnknownUnit].
+    pub fn add_unit(&mut self, unit: &str) {
+        self.graph.add_vertex(Unit::from(unit));
+        self.recompute_scales();
+    }
+
+    /// Registers a new conversion factual with the graph, inserting vertices for either unit as
+    /// needed, without rebuilding the rest of the graph. Fails if `conversion` is between
+    /// compound units (e.g. `km/h`) - facts must be between plain units; only queries may be
+    /// compound.
+    pub fn add_conversion(&mut self, conversion: UnitConversion) -> Result<(), ConversionError> {
+        let connection = Connection::try_from(&conversion)?;
+        self.has_asymmetric_facts |= conversion.is_asymmetric();
+        self.graph.add_connection(connection);
+        self.recompute_scales();
+        Ok(())
+    }
+
+    fn recompute_scales(&mut self) {
+        let (scales, _) = self.graph.compute_scales();
+        self.scales = scales;
+    }
+
+    /// Checks every conversion fact in the graph against the others, returning the
+    /// [`Inconsistency`][Inconsistency] found for each pair of facts that disagree (e.g.
+    /// contradictory facts between the same two units, or a cycle whose weights don't multiply
+    /// back to `1.0`). An empty `Vec` would never be returned as an `Err` - callers get `Ok(())`
+    /// instead.
+    pub fn validate(&self) -> Result<(), Vec<Inconsistency>> {
+        let (_, inconsistencies) = self.graph.compute_scales();
+
+        if inconsistencies.is_empty() {
+            return Ok(());
         }
+
+        Err(inconsistencies
+            .into_iter()
+            .map(
+                |GraphInconsistency {
+                     from,
+                     to,
+                     expected,
+                     found,
+                 }| Inconsistency {
+                    from: from.0,
+                    to: to.0,
+                    expected,
+                    found,
+                },
+            )
+            .collect())
     }
 
     /// Attempts to perform the requested unit conversion based on the graph of factuals that we have.
+    /// Unknown units are treated the same as an unconvertible pair; use
+    /// [`try_convert`][Self::try_convert] if you need to tell the two cases apart.
     pub fn convert(&self, query: UnitConversion) -> ConversionResult {
-        ConversionResult(self.graph.fold_path(query.from, query.to, query.value))
+        self.try_convert(query).unwrap_or(ConversionResult(None))
+    }
+
+    /// Like [`convert`][Self::convert], but reports unknown units as a
+    /// [`ConversionError`][ConversionError] rather than silently folding them into
+    /// "not convertible!".
+    pub fn try_convert(&self, query: UnitConversion) -> Result<ConversionResult, ConversionError> {
+        if self.has_asymmetric_facts {
+            return self.try_convert_via_path(&query);
+        }
+
+        let from_scale = self.compound_scale(&query.from)?;
+        let to_scale = self.compound_scale(&query.to)?;
+
+        if from_scale.dimension != to_scale.dimension {
+            return Ok(ConversionResult(None));
+        }
+
+        Ok(ConversionResult(Some(
+            query.value * to_scale.factor / from_scale.factor,
+        )))
+    }
+
+    /// Resolves a query by folding a live [`Graph::fold_path`][Graph::fold_path] over each base
+    /// unit instead of the cached scales, since those can't represent weak or one-directional
+    /// facts. Numerator units are matched between `query.from` and `query.to` by graph
+    /// reachability (same for denominator units) rather than by position, same as
+    /// [`compound_scale`][Self::compound_scale] matches by component - otherwise reordering the
+    /// terms of a commutative compound unit (e.g. `"lb*ft"` vs `"ft*lb"`) would depend on term
+    /// order instead of on physical dimension. A term that can't be paired up this way is reported
+    /// as not-convertible, same as an unreachable pair.
+    fn try_convert_via_path(
+        &self,
+        query: &UnitConversion,
+    ) -> Result<ConversionResult, ConversionError> {
+        self.ensure_known(&query.from)?;
+        self.ensure_known(&query.to)?;
+
+        let factor = match self.fold_matched_terms(
+            &query.from.numerator,
+            &query.to.numerator,
+            query.value,
+            false,
+        ) {
+            Some(factor) => factor,
+            None => return Ok(ConversionResult(None)),
+        };
+
+        let factor = match self.fold_matched_terms(
+            &query.from.denominator,
+            &query.to.denominator,
+            factor,
+            true,
+        ) {
+            Some(factor) => factor,
+            None => return Ok(ConversionResult(None)),
+        };
+
+        Ok(ConversionResult(Some(factor)))
+    }
+
+    /// Finds a one-to-one pairing between `from_units` and `to_units` (see
+    /// [`match_terms`][Self::match_terms]), then folds a [`Graph::fold_path`][Graph::fold_path]
+    /// ratio over `factor` for each matched pair (multiplying if `invert` is `false`, dividing if
+    /// it's `true`, since denominator terms need to shrink the factor rather than grow it).
+    /// Returns `None` if the two slices have different lengths, no full pairing exists between
+    /// them, or no path exists between some matched pair.
+    fn fold_matched_terms(
+        &self,
+        from_units: &[Unit],
+        to_units: &[Unit],
+        factor: f32,
+        invert: bool,
+    ) -> Option<f32> {
+        let pairing = self.match_terms(from_units, to_units)?;
+        let mut factor = factor;
+
+        for (from_index, to_index) in pairing.into_iter().enumerate() {
+            // Always fold in the from -> to direction, since a one-way fact might only have an
+            // edge that way; divide/multiply the running factor rather than assuming the reverse
+            // direction is the reciprocal.
+            let ratio = self.graph.fold_path(
+                from_units[from_index].clone(),
+                to_units[to_index].clone(),
+                1.0,
+            )?;
+            factor = if invert {
+                factor / ratio
+            } else {
+                factor * ratio
+            };
+        }
+
+        Some(factor)
+    }
+
+    /// Finds a one-to-one pairing between `from_units` and `to_units` such that every matched pair
+    /// is reachable from one another (see [`are_connected`][Self::are_connected]), i.e. belongs to
+    /// the same physical dimension - rather than relying on the cached
+    /// [`VertexScale`][VertexScale] components, which don't account for weak or one-directional
+    /// facts. Returns `None` if the slices have different lengths or no full pairing exists.
+    ///
+    /// A single greedy pass over `to_units` isn't enough here: committing to the first reachable
+    /// candidate for one `from_units` term can starve a later term of its only match, even though
+    /// a different assignment of the earlier term would've left one free. So this runs Kuhn's
+    /// algorithm instead, augmenting the matching one `from_units` term at a time and
+    /// backtracking previously-matched terms onto an alternative `to_units` candidate when doing
+    /// so frees up the one the current term needs.
+    fn match_terms(&self, from_units: &[Unit], to_units: &[Unit]) -> Option<Vec<usize>> {
+        if from_units.len() != to_units.len() {
+            return None;
+        }
+
+        // `to_index -> from_index` of the `from_units` term currently matched to it, if any.
+        let mut matched_from: Vec<Option<usize>> = vec![None; to_units.len()];
+
+        for from_index in 0..from_units.len() {
+            let mut visited = vec![false; to_units.len()];
+            if !self.augment(
+                from_units,
+                to_units,
+                from_index,
+                &mut visited,
+                &mut matched_from,
+            ) {
+                return None;
+            }
+        }
+
+        let mut pairing = vec![0; from_units.len()];
+        for (to_index, from_index) in matched_from.into_iter().enumerate() {
+            pairing[from_index
+                .expect("every to_units term is matched once all from_units terms are")] = to_index;
+        }
+
+        Some(pairing)
+    }
+
+    /// Tries to match `from_index` to some unvisited, reachable entry of `to_units`, freeing one
+    /// up by recursively re-matching its current `from_units` term if needed. Returns whether a
+    /// match was found, updating `matched_from` in place.
+    fn augment(
+        &self,
+        from_units: &[Unit],
+        to_units: &[Unit],
+        from_index: usize,
+        visited: &mut [bool],
+        matched_from: &mut [Option<usize>],
+    ) -> bool {
+        for to_index in 0..to_units.len() {
+            if visited[to_index]
+                || !self.are_connected(&from_units[from_index], &to_units[to_index])
+            {
+                continue;
+            }
+            visited[to_index] = true;
+
+            let can_match = match matched_from[to_index] {
+                None => true,
+                Some(other_from_index) => self.augment(
+                    from_units,
+                    to_units,
+                    other_from_index,
+                    visited,
+                    matched_from,
+                ),
+            };
+
+            if can_match {
+                matched_from[to_index] = Some(from_index);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Whether `a` and `b` are reachable from one another via any path (weak facts included,
+    /// either direction), i.e. whether they belong to the same physical dimension. Used by
+    /// [`match_terms`][Self::match_terms] to pair compound unit terms up regardless of order.
+    fn are_connected(&self, a: &Unit, b: &Unit) -> bool {
+        a == b
+            || self.graph.fold_path(a.clone(), b.clone(), 1.0).is_some()
+            || self.graph.fold_path(b.clone(), a.clone(), 1.0).is_some()
+    }
+
+    /// Returns an error if any base unit making up `unit` hasn't been registered with the graph.
+    fn ensure_known(&self, unit: &CompoundUnit) -> Result<(), ConversionError> {
+        unit.numerator
+            .iter()
+            .chain(unit.denominator.iter())
+            .find(|base_unit| !self.graph.contains_vertex(base_unit))
+            .map_or(Ok(()), |unit| {
+                Err(ConversionError::UnknownUnit(unit.0.clone()))
+            })
+    }
+
+    /// Folds the cached per-unit [`VertexScale`][VertexScale]s over every base unit in `unit`
+    /// (numerator multiplying, denominator dividing) to get its overall scale factor, plus the
+    /// multiset of dimensions (graph components, each with a signed power) it's made of. Two
+    /// compound units are only convertible when their dimensions match exactly.
+    fn compound_scale(&self, unit: &CompoundUnit) -> Result<CompoundScale, ConversionError> {
+        let mut factor = 1.0;
+        let mut powers: HashMap<usize, i32> = HashMap::new();
+
+        for base_unit in &unit.numerator {
+            let scale = self.scale_of(base_unit)?;
+            factor *= scale.factor;
+            *powers.entry(scale.component).or_insert(0) += 1;
+        }
+
+        for base_unit in &unit.denominator {
+            let scale = self.scale_of(base_unit)?;
+            factor /= scale.factor;
+            *powers.entry(scale.component).or_insert(0) -= 1;
+        }
+
+        let mut dimension: Vec<(usize, i32)> = powers
+            .into_iter()
+            .filter(|(_, power)| *power != 0)
+            .collect();
+        dimension.sort_unstable();
+
+        Ok(CompoundScale { factor, dimension })
+    }
+
+    fn scale_of(&self, unit: &Unit) -> Result<VertexScale, ConversionError> {
+        self.scales
+            .get(unit)
+            .copied()
+            .ok_or_else(|| ConversionError::UnknownUnit(unit.0.clone()))
+    }
+
+    /// Builds a graph from a newline-separated list of facts, e.g.:
+    ///
+    /// ```text
+    /// m to ft = 3.28
+    /// ft to in = 12.0
+    /// ```
+    ///
+    /// Blank lines are ignored. See [`FromStr` for `UnitConversion`][UnitConversion] for the
+    /// accepted fact syntax.
+    pub fn from_facts_str(facts: &str) -> Result<Self, ParseError> {
+        let facts = facts
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<UnitConversion>, ParseError>>()?;
+
+        Self::new(facts).map_err(ParseError::from)
+    }
+}
+
+/// Errors that can occur while working with a [`ConversionGraph`][ConversionGraph].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The named unit hasn't been registered with the graph via [`ConversionGraph::new`][ConversionGraph::new],
+    /// [`ConversionGraph::add_unit`][ConversionGraph::add_unit], or
+    /// [`ConversionGraph::add_conversion`][ConversionGraph::add_conversion].
+    UnknownUnit(String),
+    /// A conversion fact (as opposed to a query) was between compound units, e.g. `km/h`. Facts
+    /// must be between plain units; only queries may be compound.
+    CompoundFact(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::UnknownUnit(unit) => write!(f, "unknown unit {}", unit),
+            ConversionError::CompoundFact(unit) => write!(
+                f,
+                "conversion facts must be between plain units, got compound unit {}",
+                unit
+            ),
+        }
     }
 }
 
+impl std::error::Error for ConversionError {}
+
+/// The combined scale factor of a [`CompoundUnit`][CompoundUnit], tagged with the multiset of
+/// dimensions (graph components, each with a signed power) it represents.
+struct CompoundScale {
+    factor: f32,
+    dimension: Vec<(usize, i32)>,
+}
+
+/// A mismatch found by [`ConversionGraph::validate`][ConversionGraph::validate]: the conversion
+/// fact from `from` to `to` implies a scale factor of `expected` relative to the rest of the
+/// graph, but a different path between the two units had already established `found`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inconsistency {
+    pub from: String,
+    pub to: String,
+    pub expected: f32,
+    pub found: f32,
+}
+
 /// Struct representing our conversion units.
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
 struct Unit(String);
 
 impl From<&str> for Unit {
-    /// Helper functionality for converting str slices to [`Unit`][Unit]s.
-    /// Performs basic validation on the provided string (i.e. it must be one of the known unit
-    /// types).
+    /// Helper functionality for converting str slices to [`Unit`][Unit]s. Unit names are no
+    /// longer validated against a fixed whitelist here; a [`ConversionGraph`][ConversionGraph]
+    /// only knows about the units it was built with or has since registered, and will report
+    /// unrecognized ones via [`ConversionGraph::try_convert`][ConversionGraph::try_convert].
     fn from(value: &str) -> Self {
-        assert!(
-            VALID_UNITS.contains(value),
-            "Received invalid unit value {}",
-            value
-        );
-
         Unit(value.to_string())
     }
 }
 
+/// A unit composed of a product/quotient of base [`Unit`][Unit]s, e.g. `km/h` (numerator `{km}`,
+/// denominator `{h}`) or `ft*lb` (numerator `{ft, lb}`, denominator `{}`). A plain unit like `m`
+/// is just a compound unit with a single numerator term and no denominator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CompoundUnit {
+    numerator: Vec<Unit>,
+    denominator: Vec<Unit>,
+}
+
+impl From<&str> for CompoundUnit {
+    /// Parses a unit string into its numerator and (optional) denominator base units, split on
+    /// `/` and `*` respectively.
+    fn from(value: &str) -> Self {
+        let mut halves = value.splitn(2, '/');
+        let numerator = parse_unit_group(halves.next().unwrap_or_default());
+        let denominator = halves.next().map(parse_unit_group).unwrap_or_default();
+
+        CompoundUnit {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+fn parse_unit_group(group: &str) -> Vec<Unit> {
+    group
+        .split('*')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(Unit::from)
+        .collect()
+}
+
+impl fmt::Display for CompoundUnit {
+    /// Renders back out roughly the syntax [`CompoundUnit`][CompoundUnit] was parsed from, e.g.
+    /// `km/h` or `ft*lb`. Used for error messages.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |units: &[Unit]| {
+            units
+                .iter()
+                .map(|unit| unit.0.as_str())
+                .collect::<Vec<_>>()
+                .join("*")
+        };
+
+        write!(f, "{}", join(&self.numerator))?;
+        if !self.denominator.is_empty() {
+            write!(f, "/{}", join(&self.denominator))?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ConversionResult(pub(crate) Option<f32>);
 
@@ -62,21 +476,62 @@ impl ToString for ConversionResult {
     }
 }
 
-/// Represents a unit conversion (whether a known factual or a query)
+/// Represents a unit conversion (whether a known factual or a query). `from`/`to` may each be a
+/// plain unit (`"m"`) or a compound one (`"km/h"`, `"ft*lb"`). `reverse`/`weak` only matter when
+/// a `UnitConversion` is registered as a fact; they're ignored for queries.
 #[derive(Debug)]
 pub struct UnitConversion {
-    from: Unit,
-    to: Unit,
+    from: CompoundUnit,
+    to: CompoundUnit,
     value: f32,
+    reverse: ReverseWeight,
+    weak: bool,
+}
+
+impl UnitConversion {
+    /// Whether this fact can't be represented as a single symmetric scale factor: either it's
+    /// one-directional, its reverse weight isn't the reciprocal, or it's weak (fallback-only).
+    fn is_asymmetric(&self) -> bool {
+        self.weak || self.reverse != ReverseWeight::Inverse
+    }
 }
 
-impl From<&UnitConversion> for Connection<Unit> {
-    fn from(conversion: &UnitConversion) -> Self {
-        Self::new(
-            conversion.from.clone(),
-            conversion.to.clone(),
+impl TryFrom<&UnitConversion> for Connection<Unit> {
+    type Error = ConversionError;
+
+    /// Conversion facts (as opposed to queries) must be between plain, non-compound units. Fails
+    /// with [`ConversionError::CompoundFact`][ConversionError::CompoundFact] otherwise.
+    fn try_from(conversion: &UnitConversion) -> Result<Self, Self::Error> {
+        let as_plain_unit = |compound: &CompoundUnit| {
+            compound
+                .as_unit()
+                .cloned()
+                .ok_or_else(|| ConversionError::CompoundFact(compound.to_string()))
+        };
+
+        let connection = Self::new(
+            as_plain_unit(&conversion.from)?,
+            as_plain_unit(&conversion.to)?,
             conversion.value,
         )
+        .with_reverse(conversion.reverse);
+
+        Ok(if conversion.weak {
+            connection.weak()
+        } else {
+            connection
+        })
+    }
+}
+
+impl CompoundUnit {
+    /// Returns the sole base unit this represents, if it's just a plain unit (a single numerator
+    /// term and no denominator).
+    fn as_unit(&self) -> Option<&Unit> {
+        match (self.numerator.as_slice(), self.denominator.as_slice()) {
+            ([unit], []) => Some(unit),
+            _ => None,
+        }
     }
 }
 
@@ -84,9 +539,109 @@ impl UnitConversion {
     /// Helper for initializing a `UnitConversion` from str slices
     pub fn new(from: &str, to: &str, value: f32) -> Self {
         UnitConversion {
-            from: Unit::from(from),
-            to: Unit::from(to),
+            from: CompoundUnit::from(from),
+            to: CompoundUnit::from(to),
             value,
+            reverse: ReverseWeight::Inverse,
+            weak: false,
+        }
+    }
+
+    /// Overrides how the reverse edge's weight (if any) is derived from this fact when it's
+    /// registered with a [`ConversionGraph`][ConversionGraph]. Has no effect on a query.
+    pub fn with_reverse(mut self, reverse: ReverseWeight) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Marks this fact as weak: once registered, it'll only be used as a fallback when no path
+    /// made up of non-weak facts already connects the two units. Has no effect on a query.
+    pub fn weak(mut self) -> Self {
+        self.weak = true;
+        self
+    }
+}
+
+/// The implicit value given to a query that doesn't specify an amount, e.g. `"m to ft"`.
+const DEFAULT_QUERY_VALUE: f32 = 1.0;
+
+impl FromStr for UnitConversion {
+    type Err = ParseError;
+
+    /// Parses either a conversion fact, e.g. `"m to ft = 3.28"`, or a query, e.g. `"2 m to in"`.
+    /// A query with no leading amount, e.g. `"m to in"`, is treated as a query for
+    /// [`DEFAULT_QUERY_VALUE`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (expr, factor) = match s.split_once('=') {
+            Some((expr, factor)) => (expr.trim(), Some(factor.trim())),
+            None => (s.trim(), None),
+        };
+
+        let tokens: Vec<&str> = expr.split_whitespace().collect();
+
+        let (amount, from, to) = match tokens.as_slice() {
+            [amount, from, "to", to] => {
+                let amount = amount
+                    .parse::<f32>()
+                    .map_err(|_| ParseError::InvalidNumber(amount.to_string()))?;
+                (amount, *from, *to)
+            }
+            [from, "to", to] => (DEFAULT_QUERY_VALUE, *from, *to),
+            _ => return Err(ParseError::MalformedExpression(s.to_string())),
+        };
+
+        let value = match factor {
+            Some(factor) => factor
+                .parse::<f32>()
+                .map_err(|_| ParseError::InvalidNumber(factor.to_string()))?,
+            None => amount,
+        };
+
+        Ok(UnitConversion::new(from, to, value))
+    }
+}
+
+/// Errors produced while parsing a [`UnitConversion`][UnitConversion] or a set of facts via
+/// [`ConversionGraph::from_facts_str`][ConversionGraph::from_facts_str].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A unit hasn't been registered with the graph (converted from
+    /// [`ConversionError::UnknownUnit`][ConversionError::UnknownUnit]).
+    UnknownUnit(String),
+    /// The expression didn't match the `"<unit> to <unit>"`, `"<unit> to <unit> = <factor>"`, or
+    /// `"<amount> <unit> to <unit>"` shapes.
+    MalformedExpression(String),
+    /// A number (amount or factor) couldn't be parsed as an `f32`.
+    InvalidNumber(String),
+    /// A conversion fact was between compound units, e.g. `km/h`. Facts must be between plain
+    /// units; only queries may be compound.
+    CompoundFact(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownUnit(unit) => write!(f, "unknown unit {}", unit),
+            ParseError::MalformedExpression(expr) => {
+                write!(f, "malformed conversion expression: {}", expr)
+            }
+            ParseError::InvalidNumber(value) => write!(f, "invalid number: {}", value),
+            ParseError::CompoundFact(unit) => write!(
+                f,
+                "conversion facts must be between plain units, got compound unit {}",
+                unit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ConversionError> for ParseError {
+    fn from(err: ConversionError) -> Self {
+        match err {
+            ConversionError::UnknownUnit(unit) => ParseError::UnknownUnit(unit),
+            ConversionError::CompoundFact(unit) => ParseError::CompoundFact(unit),
         }
     }
 }
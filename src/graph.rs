@@ -60,6 +60,9 @@ impl<T> ArcVertex<T> {
 struct Edge<T> {
     weight: f32,
     to: WeakVertex<T>,
+    // Whether this edge should only be used as a fallback, i.e. when no path made up of
+    // non-weak edges already connects its two endpoints.
+    weak: bool,
 }
 
 /// Graph vertex, containing a value and a vector of edges
@@ -69,6 +72,19 @@ struct Vertex<T> {
     edges: Vec<Edge<T>>,
 }
 
+/// Determines how the (implicit) reverse edge for a [`Connection`][Connection] gets its weight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReverseWeight {
+    /// The reverse edge's weight is the reciprocal of the forward one. This was the only
+    /// behavior available before callers could configure it, and remains the default.
+    Inverse,
+    /// The reverse edge uses the given weight instead of the reciprocal, for relationships
+    /// (e.g. affine ones) where the two directions aren't simple reciprocals of each other.
+    Explicit(f32),
+    /// No reverse edge is added at all; the connection can only be traversed forwards.
+    None,
+}
+
 pub struct Connection<T>
 where
     T: Hash + Eq + PartialEq + Clone,
@@ -76,6 +92,8 @@ where
     from: T,
     to: T,
     value: f32,
+    reverse: ReverseWeight,
+    weak: bool,
 }
 
 impl<T> Connection<T>
@@ -83,7 +101,27 @@ where
     T: Hash + Eq + PartialEq + Clone + Debug,
 {
     pub fn new(from: T, to: T, value: f32) -> Self {
-        Self { from, to, value }
+        Self {
+            from,
+            to,
+            value,
+            reverse: ReverseWeight::Inverse,
+            weak: false,
+        }
+    }
+
+    /// Overrides how the reverse edge's weight (if any) is derived from this connection's weight.
+    pub fn with_reverse(mut self, reverse: ReverseWeight) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Marks this connection (both directions, if a reverse edge exists) as weak: it'll only be
+    /// used by [`Graph::fold_path`][Graph::fold_path] as a fallback, when no path made up of
+    /// non-weak connections already connects its two endpoints.
+    pub fn weak(mut self) -> Self {
+        self.weak = true;
+        self
     }
 }
 
@@ -98,42 +136,79 @@ where
 {
     /// Helper method for creating a graph from a vec of connections
     pub fn new(connections: Vec<Connection<T>>) -> Self {
-        let mut vertex_map: HashMap<T, ArcVertex<T>> = HashMap::new();
-
-        // create a map of our Vertex pairs
-        connections.iter().for_each(|fact| {
-            vertex_map.insert(fact.to.clone(), ArcVertex::from(fact.to.clone()));
-
-            vertex_map.insert(fact.from.clone(), ArcVertex::from(fact.from.clone()));
-        });
-
-        // create the edges between our vertices
-        connections.iter().for_each(|fact| {
-            if let (Some(origin), Some(destination)) =
-                (vertex_map.get(&fact.from), vertex_map.get(&fact.to))
-            {
-                origin.add_edge(Edge {
-                    weight: fact.value,
-                    to: destination.weak_ref(),
-                });
+        let mut graph = Self {
+            vertices: HashMap::new(),
+        };
+
+        connections
+            .into_iter()
+            .for_each(|connection| graph.add_connection(connection));
+
+        graph
+    }
+
+    /// Inserts a vertex for `value` if one doesn't already exist, leaving the rest of the graph
+    /// untouched.
+    pub fn add_vertex(&mut self, value: T) {
+        self.vertices
+            .entry(value.clone())
+            .or_insert_with(|| ArcVertex::from(value));
+    }
+
+    /// Adds a connection to the graph, inserting vertices for either end as needed, without
+    /// rebuilding the rest of the graph.
+    pub fn add_connection(&mut self, connection: Connection<T>) {
+        self.add_vertex(connection.from.clone());
+        self.add_vertex(connection.to.clone());
+
+        if let (Some(origin), Some(destination)) = (
+            self.vertices.get(&connection.from),
+            self.vertices.get(&connection.to),
+        ) {
+            origin.add_edge(Edge {
+                weight: connection.value,
+                to: destination.weak_ref(),
+                weak: connection.weak,
+            });
 
+            let reverse_weight = match connection.reverse {
+                ReverseWeight::Inverse => Some(1.0 / connection.value),
+                ReverseWeight::Explicit(weight) => Some(weight),
+                ReverseWeight::None => None,
+            };
+
+            if let Some(weight) = reverse_weight {
                 destination.add_edge(Edge {
-                    // assume the weight from destination to origin is the inverse of the given one
-                    // TODO: we could allow the caller of `new` to specify how to determine weights
-                    weight: 1.0 / fact.value,
+                    weight,
                     to: origin.weak_ref(),
+                    weak: connection.weak,
                 });
             }
-        });
-
-        Self {
-            vertices: vertex_map,
         }
     }
 
+    /// Returns whether a vertex for `value` already exists in the graph.
+    pub fn contains_vertex(&self, value: &T) -> bool {
+        self.vertices.contains_key(value)
+    }
+
     /// Traverse the graph via BFS looking for the vertex containing the target value.
-    /// Returns a vector of [`Edge`][Edge]s forming a path between the two vertices.
+    /// Returns a vector of [`Edge`][Edge]s forming a path between the two vertices. Weak edges
+    /// are only considered as a fallback, in a second pass, if no path using non-weak edges
+    /// alone could be found.
     fn find_path(&self, starting_value: T, target_value: T) -> Option<Vec<Edge<T>>> {
+        self.find_path_filtered(&starting_value, &target_value, false)
+            .or_else(|| self.find_path_filtered(&starting_value, &target_value, true))
+    }
+
+    /// Same as [`find_path`][Self::find_path], but `allow_weak` controls whether weak edges may
+    /// be traversed at all.
+    fn find_path_filtered(
+        &self,
+        starting_value: &T,
+        target_value: &T,
+        allow_weak: bool,
+    ) -> Option<Vec<Edge<T>>> {
         // Tracker for visited vertices. We keep a set of seen values rather than vertices
         // themselves, because hashing vertices has some additional challenges
         // https://github.com/rust-lang/rust/issues/39128.
@@ -142,7 +217,7 @@ where
         // queue for storing vertices and the path to them
         let mut queue = VecDeque::new();
 
-        let starting_vertex = self.vertices.get(&starting_value)?.clone();
+        let starting_vertex = self.vertices.get(starting_value)?.clone();
         // our starting path is empty, because we're storing edge, and we haven't traversed any yet
         let starting_path = Vec::new();
 
@@ -152,21 +227,25 @@ where
             let vertex_lock = curr_vertex.read_lock();
             visited.insert(vertex_lock.value.clone());
 
-            if vertex_lock.value == target_value {
+            if &vertex_lock.value == target_value {
                 // found the target
                 return Some(path);
             }
 
-            vertex_lock.edges.iter().for_each(|edge| {
-                let next: ArcVertex<T> = edge.into();
-                let next_value = &next.read_lock().value;
+            vertex_lock
+                .edges
+                .iter()
+                .filter(|edge| allow_weak || !edge.weak)
+                .for_each(|edge| {
+                    let next: ArcVertex<T> = edge.into();
+                    let next_value = &next.read_lock().value;
 
-                if !visited.contains(next_value) {
-                    let new_path: Vec<Edge<T>> =
-                        path.iter().cloned().chain(once(edge.clone())).collect();
-                    queue.push_front((next.clone(), new_path));
-                }
-            });
+                    if !visited.contains(next_value) {
+                        let new_path: Vec<Edge<T>> =
+                            path.iter().cloned().chain(once(edge.clone())).collect();
+                        queue.push_front((next.clone(), new_path));
+                    }
+                });
         }
 
         None
@@ -180,4 +259,107 @@ where
             .fold(value, |acc, edge| acc * edge.weight)
             .into()
     }
+
+    /// Walks every connected component via BFS, assigning each vertex a [`VertexScale`][VertexScale]
+    /// relative to an arbitrary representative of its component (the first vertex visited in the
+    /// component, which gets a factor of `1.0`). Whenever an edge leads to a vertex that's already
+    /// been scaled, the factor implied by that edge is checked against the existing one, and any
+    /// mismatch (outside of [`SCALE_EPSILON`][SCALE_EPSILON]) is collected as an
+    /// [`Inconsistency`][Inconsistency] rather than causing the walk to fail.
+    ///
+    /// Weak edges are ignored entirely here: a single scale factor can't soundly represent a
+    /// fallback-only relationship, so callers that need to account for weak (or one-directional)
+    /// edges should fall back to [`fold_path`][Self::fold_path] instead.
+    pub fn compute_scales(&self) -> (HashMap<T, VertexScale>, Vec<Inconsistency<T>>) {
+        let mut scales: HashMap<T, VertexScale> = HashMap::new();
+        let mut inconsistencies = Vec::new();
+        let mut component = 0;
+
+        for (value, vertex) in &self.vertices {
+            if scales.contains_key(value) {
+                continue;
+            }
+
+            scales.insert(
+                value.clone(),
+                VertexScale {
+                    component,
+                    factor: 1.0,
+                },
+            );
+
+            let mut queue = VecDeque::new();
+            queue.push_back(vertex.clone());
+
+            while let Some(current) = queue.pop_front() {
+                let current_lock = current.read_lock();
+                let current_scale = scales[&current_lock.value];
+
+                current_lock
+                    .edges
+                    .iter()
+                    .filter(|edge| !edge.weak)
+                    .for_each(|edge| {
+                        let next: ArcVertex<T> = edge.into();
+                        let next_lock = next.read_lock();
+                        let expected = current_scale.factor * edge.weight;
+
+                        match scales.get(&next_lock.value) {
+                            Some(existing) => {
+                                if !scales_match(existing.factor, expected) {
+                                    inconsistencies.push(Inconsistency {
+                                        from: current_lock.value.clone(),
+                                        to: next_lock.value.clone(),
+                                        expected,
+                                        found: existing.factor,
+                                    });
+                                }
+                            }
+                            None => {
+                                scales.insert(
+                                    next_lock.value.clone(),
+                                    VertexScale {
+                                        component,
+                                        factor: expected,
+                                    },
+                                );
+                                queue.push_back(next.clone());
+                            }
+                        }
+                    });
+            }
+
+            component += 1;
+        }
+
+        (scales, inconsistencies)
+    }
+}
+
+/// The scale factor assigned to a vertex relative to an arbitrary representative of its connected
+/// component, plus an id identifying that component (so factors are only ever compared between
+/// vertices that share one).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VertexScale {
+    pub component: usize,
+    pub factor: f32,
+}
+
+/// A mismatch discovered by [`Graph::compute_scales`][Graph::compute_scales]: traversing the edge
+/// from `from` to `to` implies a scale factor of `expected`, but `to` had already been assigned
+/// `found` via a different path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inconsistency<T> {
+    pub from: T,
+    pub to: T,
+    pub expected: f32,
+    pub found: f32,
+}
+
+/// How far apart two scale factors may be, relative to their magnitude, before they're considered
+/// inconsistent.
+const SCALE_EPSILON: f32 = 1e-3;
+
+fn scales_match(a: f32, b: f32) -> bool {
+    (a - b).abs() <= SCALE_EPSILON * a.abs().max(b.abs())
 }
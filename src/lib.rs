@@ -0,0 +1,5 @@
+pub mod conversion;
+mod graph;
+
+#[cfg(test)]
+mod tests;
@@ -1,8 +1,27 @@
+use lazy_static::lazy_static;
+
+use crate::conversion::{ConversionGraph, UnitConversion};
+
+// Rust has no support for complex constant initialization (since constants are initialized at
+// compile time), so we're using the lazy_static crate here to lazily init our shared
+// test graph at runtime instead
+lazy_static! {
+    static ref TEST_GRAPH: ConversionGraph = ConversionGraph::new(vec![
+        UnitConversion::new("m", "ft", 3.28),
+        UnitConversion::new("ft", "in", 12.0),
+        UnitConversion::new("hr", "min", 60.0),
+        UnitConversion::new("min", "sec", 60.0),
+    ])
+    .unwrap();
+}
+
 #[cfg(test)]
 mod test_convert {
     use approx::assert_relative_eq;
 
-    use crate::{ConversionResult, UnitConversion, TEST_GRAPH};
+    use crate::conversion::{ConversionResult, UnitConversion};
+
+    use super::TEST_GRAPH;
 
     #[test]
     fn it_works_for_m_to_in() {
@@ -32,3 +51,312 @@ mod test_convert {
         assert_eq!(res, ConversionResult(None));
     }
 }
+
+#[cfg(test)]
+mod test_registry {
+    use approx::assert_relative_eq;
+
+    use crate::conversion::{ConversionError, ConversionGraph, UnitConversion};
+
+    #[test]
+    fn it_registers_new_units_and_conversions_after_construction() {
+        let mut graph = ConversionGraph::new(vec![UnitConversion::new("m", "ft", 3.28)]).unwrap();
+
+        graph.add_unit("yd");
+        graph
+            .add_conversion(UnitConversion::new("yd", "ft", 3.0))
+            .unwrap();
+
+        let res = graph
+            .try_convert(UnitConversion::new("m", "yd", 1.0))
+            .unwrap();
+
+        assert_relative_eq!(res.0.unwrap(), 3.28 / 3.0);
+    }
+
+    #[test]
+    fn it_reports_unknown_units_instead_of_panicking() {
+        let graph = ConversionGraph::new(vec![UnitConversion::new("m", "ft", 3.28)]).unwrap();
+
+        let res = graph.try_convert(UnitConversion::new("m", "parsec", 1.0));
+
+        assert_eq!(res, Err(ConversionError::UnknownUnit("parsec".to_string())));
+    }
+
+    #[test]
+    fn it_reports_a_compound_fact_instead_of_panicking() {
+        let err = ConversionGraph::new(vec![UnitConversion::new("km/h", "m/s", 0.27778)])
+            .map(|_| ())
+            .unwrap_err();
+
+        assert_eq!(err, ConversionError::CompoundFact("km/h".to_string()));
+    }
+
+    #[test]
+    fn it_reports_a_compound_fact_added_after_construction_instead_of_panicking() {
+        let mut graph = ConversionGraph::new(vec![UnitConversion::new("m", "ft", 3.28)]).unwrap();
+
+        let err = graph
+            .add_conversion(UnitConversion::new("km/h", "m/s", 0.27778))
+            .unwrap_err();
+
+        assert_eq!(err, ConversionError::CompoundFact("km/h".to_string()));
+    }
+}
+
+#[cfg(test)]
+mod test_parsing {
+    use approx::assert_relative_eq;
+
+    use crate::conversion::{ConversionGraph, ParseError, UnitConversion};
+
+    #[test]
+    fn it_parses_a_fact() {
+        let graph = ConversionGraph::new(vec!["m to ft = 3.28".parse().unwrap()]).unwrap();
+
+        let res = graph.convert(UnitConversion::new("m", "ft", 1.0));
+
+        assert_relative_eq!(res.0.unwrap(), 3.28);
+    }
+
+    #[test]
+    fn it_parses_a_query_with_an_amount() {
+        let graph = ConversionGraph::new(vec![UnitConversion::new("m", "in", 39.37)]).unwrap();
+
+        let res = graph.convert("2 m to in".parse().unwrap());
+
+        assert_relative_eq!(res.0.unwrap(), 2.0 * 39.37);
+    }
+
+    #[test]
+    fn it_defaults_a_bare_query_to_an_amount_of_one() {
+        let graph = ConversionGraph::new(vec![UnitConversion::new("m", "in", 39.37)]).unwrap();
+
+        let res = graph.convert("m to in".parse().unwrap());
+
+        assert_relative_eq!(res.0.unwrap(), 39.37);
+    }
+
+    #[test]
+    fn it_rejects_malformed_expressions() {
+        let err = "m ft".parse::<UnitConversion>().unwrap_err();
+
+        assert_eq!(err, ParseError::MalformedExpression("m ft".to_string()));
+    }
+
+    #[test]
+    fn it_rejects_bad_floats() {
+        let err = "m to ft = abc".parse::<UnitConversion>().unwrap_err();
+
+        assert_eq!(err, ParseError::InvalidNumber("abc".to_string()));
+    }
+
+    #[test]
+    fn it_builds_a_graph_from_a_facts_str() {
+        let graph = ConversionGraph::from_facts_str("m to ft = 3.28\nft to in = 12.0").unwrap();
+
+        let res = graph.convert("2 m to in".parse().unwrap());
+
+        assert_relative_eq!(res.0.unwrap(), 2.0 * 3.28 * 12.0);
+    }
+
+    #[test]
+    fn it_rejects_a_compound_fact_in_a_facts_str() {
+        let err = ConversionGraph::from_facts_str("km/h to m/s = 0.27778")
+            .map(|_| ())
+            .unwrap_err();
+
+        assert_eq!(err, ParseError::CompoundFact("km/h".to_string()));
+    }
+}
+
+#[cfg(test)]
+mod test_validate {
+    use crate::conversion::{ConversionGraph, UnitConversion};
+
+    #[test]
+    fn it_accepts_a_consistent_fact_set() {
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("m", "ft", 3.28),
+            UnitConversion::new("ft", "in", 12.0),
+        ])
+        .unwrap();
+
+        assert_eq!(graph.validate(), Ok(()));
+    }
+
+    #[test]
+    fn it_rejects_contradictory_facts() {
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("m", "ft", 3.28),
+            UnitConversion::new("m", "ft", 3.30),
+        ])
+        .unwrap();
+
+        let inconsistencies = graph.validate().unwrap_err();
+
+        assert!(!inconsistencies.is_empty());
+        assert!(inconsistencies
+            .iter()
+            .any(|i| i.from == "m" && i.to == "ft"));
+    }
+
+    #[test]
+    fn it_rejects_a_cycle_whose_weights_dont_multiply_to_one() {
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("a", "b", 2.0),
+            UnitConversion::new("b", "c", 2.0),
+            UnitConversion::new("a", "c", 3.0),
+        ])
+        .unwrap();
+
+        assert!(graph.validate().is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_compound_units {
+    use approx::assert_relative_eq;
+
+    use crate::conversion::{ConversionGraph, UnitConversion};
+
+    #[test]
+    fn it_converts_km_per_h_to_m_per_s() {
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("km", "m", 1000.0),
+            UnitConversion::new("hr", "sec", 3600.0),
+        ])
+        .unwrap();
+
+        let res = graph.convert("36 km/hr to m/sec".parse().unwrap());
+
+        assert_relative_eq!(res.0.unwrap(), 10.0);
+    }
+
+    #[test]
+    fn it_converts_a_product_of_units() {
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("ft", "m", 0.3048),
+            UnitConversion::new("lb", "N", 4.448),
+        ])
+        .unwrap();
+
+        let res = graph.convert("1 ft*lb to m*N".parse().unwrap());
+
+        assert_relative_eq!(res.0.unwrap(), 0.3048 * 4.448);
+    }
+
+    #[test]
+    fn it_does_not_convert_mismatched_dimensions() {
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("km", "m", 1000.0),
+            UnitConversion::new("hr", "sec", 3600.0),
+        ])
+        .unwrap();
+
+        let res = graph.convert("36 km/hr to m".parse().unwrap());
+
+        assert_eq!(res.0, None);
+    }
+}
+
+#[cfg(test)]
+mod test_reverse_weight {
+    use approx::assert_relative_eq;
+
+    use crate::conversion::{ConversionGraph, ReverseWeight, UnitConversion};
+
+    #[test]
+    fn it_uses_an_explicit_reverse_weight_instead_of_the_reciprocal() {
+        // affine-ish relationship: going c -> f adds 32 after scaling, so neither direction is
+        // the other's reciprocal. We only model the linear part here, but the two directions
+        // still shouldn't be forced to be reciprocals of one another.
+        let graph = ConversionGraph::new(vec![UnitConversion::new("c_scaled", "f_scaled", 1.8)
+            .with_reverse(ReverseWeight::Explicit(0.4))])
+        .unwrap();
+
+        let forward = graph.convert(UnitConversion::new("c_scaled", "f_scaled", 10.0));
+        let backward = graph.convert(UnitConversion::new("f_scaled", "c_scaled", 10.0));
+
+        assert_relative_eq!(forward.0.unwrap(), 18.0);
+        assert_relative_eq!(backward.0.unwrap(), 4.0);
+    }
+
+    #[test]
+    fn it_only_allows_traversal_in_the_declared_direction() {
+        let graph =
+            ConversionGraph::new(vec![UnitConversion::new("one_way_a", "one_way_b", 2.0)
+                .with_reverse(ReverseWeight::None)])
+            .unwrap();
+
+        let forward = graph.convert(UnitConversion::new("one_way_a", "one_way_b", 1.0));
+        let backward = graph.convert(UnitConversion::new("one_way_b", "one_way_a", 1.0));
+
+        assert_relative_eq!(forward.0.unwrap(), 2.0);
+        assert_eq!(backward.0, None);
+    }
+
+    #[test]
+    fn it_matches_compound_terms_by_dimension_not_position_once_a_fact_is_asymmetric() {
+        // an unrelated one-way fact forces the whole graph onto the live-path fallback, but it
+        // shouldn't affect conversions between units it has nothing to do with.
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("ft", "m", 0.3048),
+            UnitConversion::new("lb", "N", 4.448),
+            UnitConversion::new("one_way_a", "one_way_b", 2.0).with_reverse(ReverseWeight::None),
+        ])
+        .unwrap();
+
+        let ordered = graph.convert("1 ft*lb to m*N".parse().unwrap());
+        let reordered = graph.convert("1 lb*ft to m*N".parse().unwrap());
+
+        assert_relative_eq!(ordered.0.unwrap(), 0.3048 * 4.448);
+        assert_relative_eq!(reordered.0.unwrap(), 0.3048 * 4.448);
+    }
+
+    #[test]
+    fn it_backtracks_when_a_greedy_term_match_would_strand_another_term() {
+        // both b1 (directly) and b2 (via b1) are reachable from a1, but only b2 is reachable from
+        // a2 - a greedy match that commits a1 to b2 first would leave a2 with no match left.
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("a1", "b1", 2.0),
+            UnitConversion::new("b1", "b2", 1.0).with_reverse(ReverseWeight::None),
+            UnitConversion::new("a2", "b2", 3.0).with_reverse(ReverseWeight::None),
+        ])
+        .unwrap();
+
+        let ordered = graph.convert("1 a1*a2 to b1*b2".parse().unwrap());
+        let reordered = graph.convert("1 a1*a2 to b2*b1".parse().unwrap());
+
+        assert_relative_eq!(ordered.0.unwrap(), 6.0);
+        assert_relative_eq!(reordered.0.unwrap(), 6.0);
+    }
+
+    #[test]
+    fn it_only_falls_back_to_a_weak_fact_when_no_strong_path_exists() {
+        let graph = ConversionGraph::new(vec![
+            UnitConversion::new("strong_a", "strong_b", 2.0),
+            UnitConversion::new("strong_b", "strong_c", 3.0),
+            // a weak, less precise shortcut between the same two units
+            UnitConversion::new("strong_a", "strong_c", 100.0).weak(),
+        ])
+        .unwrap();
+
+        let res = graph.convert(UnitConversion::new("strong_a", "strong_c", 1.0));
+
+        assert_relative_eq!(res.0.unwrap(), 6.0);
+    }
+
+    #[test]
+    fn it_uses_a_weak_fact_when_it_is_the_only_path() {
+        let graph =
+            ConversionGraph::new(vec![
+                UnitConversion::new("weak_only_a", "weak_only_b", 5.0).weak()
+            ])
+            .unwrap();
+
+        let res = graph.convert(UnitConversion::new("weak_only_a", "weak_only_b", 1.0));
+
+        assert_relative_eq!(res.0.unwrap(), 5.0);
+    }
+}